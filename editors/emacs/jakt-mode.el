@@ -48,11 +48,11 @@
              (jakt-macro '("defer" "unsafe" "throw" "try" "catch" "cpp")) 
              (jakt-var-decls '("mut" "let" "anon" "raw"))
              (jakt-types '("i8" "i16" "i32" "i16" "i32" "i64" "u8" "u16" "u32"
-                           "u64" "f32" "f64" "bool" "c_int" "c_char" "usize"
+                           "u64" "f32" "f64" "bool" "c_int" "c_char" "c_string" "usize"
                            "String" "void"))
              (jakt-builtin-fn '("print" "println")) 
              (jakt-operators '("not" "and" "or" "as" "in")) 
-             (jakt-structure '("struct" "class" "enum" "namespace")) 
+             (jakt-structure '("struct" "class" "union" "enum" "namespace")) 
              (jakt-constant '("this"))
              (jakt-visibility '("public" "private")) 
 
@@ -65,6 +65,7 @@ let s:jakt_syntax_keywords = {
     \ ,              "f64"
     \ ,              "bool"
     \ ,              "c_int"
+    \ ,              "c_string"
     \ ,              "c_char"
     \ ,              "usize"
     \ ,              "void"
@@ -73,6 +74,7 @@ let s:jakt_syntax_keywords = {
     \ ,                 ]
     \ , 'jaktStructure' :["struct"
     \ ,                   "class"
+    \ ,                   "union"
     \ ,                   "enum"
     \ ,                   "namespace"
     \ ,                  ]
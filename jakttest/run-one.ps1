@@ -19,11 +19,18 @@ $jakt_args = @("$file",
     "$temp_dir",
     "-o",
     "output",
-    "-S"
+    "-S",
+    # The warnings that are off by default are turned on, so that `warning` tests can check them.
+    "-Wshadow",
+    "-Wimplicit-copy"
 )
+if ($Args[3]) {
+    $jakt_args += $Args[3] -split ' '
+}
 
-# Generate C++ code
-$jakt_process = Start-Process .\build\jakt.exe -ArgumentList $jakt_args -RedirectStandardError "$temp_dir\compile_jakt.err" -PassThru -Wait -NoNewWindow
+# Generate C++ code (or C code, for tests using --backend=c), after removing what the previous test generated
+Remove-Item "$temp_dir\output.cpp", "$temp_dir\output.c" -ErrorAction SilentlyContinue
+$jakt_process = Start-Process .\build\jakt.exe -ArgumentList $jakt_args -RedirectStandardOutput "$temp_dir\compile_jakt.out" -RedirectStandardError "$temp_dir\compile_jakt.err" -PassThru -Wait -NoNewWindow
 if ($jakt_process.ExitCode -ne 0) {
 	exit 3
 }
@@ -48,7 +55,24 @@ $clang_args = @( "-fdiagnostics-color=always",
     "$temp_dir\output.cpp"
 )
 
-$clang_process = Start-Process clang++ -ArgumentList $clang_args -RedirectStandardError "$temp_dir\compile_cpp.err" -PassThru -Wait -NoNewWindow
+$clang_path = "clang++"
+if (Test-Path "$temp_dir\output.c") {
+    $clang_path = "clang"
+    $clang_args = @( "-fdiagnostics-color=always",
+        "--target=x86_64-pc-windows-msvc",
+        "-std=c99",
+        "-Wno-parentheses-equality",
+        "-fuse-ld=lld",
+        "-O0",
+        "-Iruntime",
+        "-DJAKT_CONTINUE_ON_PANIC",
+        "-o",
+        "$temp_dir/output.exe",
+        "$temp_dir\output.c"
+    )
+}
+
+$clang_process = Start-Process $clang_path -ArgumentList $clang_args -RedirectStandardError "$temp_dir\compile_cpp.err" -PassThru -Wait -NoNewWindow
 
 if ($clang_process.ExitCode -ne 0) {
 	exit 2
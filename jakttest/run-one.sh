@@ -4,7 +4,7 @@
 #
 # SPDX-License-Identifier: BSD-2-Clause
 
-# run-one.sh <temp-dir> <file>
+# run-one.sh <temp-dir> <file> [<flags>]
 # NOTE: we need this because selfhost doesn't yet have a way to specify
 # build directories, and it doesn't use temporary directories for
 # temporary building (i.e building and running)
@@ -18,22 +18,36 @@ file=$2
 
 file_cwd=$(dirname $file)
 
-# Generate C++ code into 
-$(build/jakt $2 -B $temp_dir -o output -S 2>$temp_dir/compile_jakt.err) || exit 3
+# The warnings that are off by default are turned on, so that `warning` tests can check them.
+# Generate C++ code (or C code, for tests using --backend=c) into the temporary directory,
+# after removing what the previous test generated there.
+rm -f $temp_dir/output.cpp $temp_dir/output.c
+build/jakt $2 -B $temp_dir -o output -S -Wshadow -Wimplicit-copy $3 >$temp_dir/compile_jakt.out 2>$temp_dir/compile_jakt.err || exit 3
 
-# Compile C++ code
-clang++ -fdiagnostics-color=always \
-    -std=c++20 \
-    -Wno-unknown-warning-option \
-    -Wno-trigraphs \
-    -Wno-parentheses-equality \
-    -Wno-unqualified-std-cast-call \
-    -Wno-user-defined-literals \
-    -Wno-deprecated-declarations \
-    -Iruntime \
-    -DJAKT_CONTINUE_ON_PANIC \
-    -o $temp_dir/output \
-    $temp_dir/output.cpp 2>$temp_dir/compile_cpp.err || exit 2
+if [ -f $temp_dir/output.c ]; then
+    # Compile C code
+    clang -fdiagnostics-color=always \
+        -std=c99 \
+        -Wno-parentheses-equality \
+        -Iruntime \
+        -DJAKT_CONTINUE_ON_PANIC \
+        -o $temp_dir/output \
+        $temp_dir/output.c 2>$temp_dir/compile_cpp.err || exit 2
+else
+    # Compile C++ code
+    clang++ -fdiagnostics-color=always \
+        -std=c++20 \
+        -Wno-unknown-warning-option \
+        -Wno-trigraphs \
+        -Wno-parentheses-equality \
+        -Wno-unqualified-std-cast-call \
+        -Wno-user-defined-literals \
+        -Wno-deprecated-declarations \
+        -Iruntime \
+        -DJAKT_CONTINUE_ON_PANIC \
+        -o $temp_dir/output \
+        $temp_dir/output.cpp 2>$temp_dir/compile_cpp.err || exit 2
+fi
 
 # Run the executable inside the parent directory of the test file
 cd $file_cwd
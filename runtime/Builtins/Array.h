@@ -248,7 +248,7 @@ public:
     T& operator[](size_t index) { return at(index); }
 
     template<Integral U>
-    ArraySlice<T> operator[](Range<U> range) const { return slice_range(range.start, range.end); }
+    ArraySlice<T> operator[](Range<U> range) const { return slice_range(range.start, range.is_inclusive ? range.end + 1 : range.end); }
 
     ErrorOr<void> ensure_capacity(size_t capacity)
     {
@@ -298,6 +298,15 @@ public:
         return value;
     }
 
+    ErrorOr<Array> clone() const
+    {
+        auto array = TRY(create_empty());
+        TRY(array.ensure_capacity(size()));
+        for (size_t i = 0; i < size(); ++i)
+            TRY(array.push(at(i)));
+        return array;
+    }
+
     static ErrorOr<Array> filled(size_t size, T value)
     {
         auto array = TRY(create_empty());
@@ -394,7 +403,7 @@ public:
     T& operator[](size_t index) { return at(index); }
 
     template<Integral U>
-    ArraySlice<T> operator[](Range<U> range) const { return slice_range(range.start, range.end); }
+    ArraySlice<T> operator[](Range<U> range) const { return slice_range(range.start, range.is_inclusive ? range.end + 1 : range.end); }
 
     ArraySlice<T> slice_range(size_t from, size_t to) const
     {
@@ -452,6 +461,17 @@ ArraySlice<T> ArraySlice<T>::slice(size_t offset, size_t size) const
 }
 }
 
+namespace Jakt {
+template<typename T, size_t Size>
+template<typename U>
+JaktInternal::ArrayIterator<U> LinearArray<T, Size>::iterator() const
+{
+    auto storage = MUST(adopt_nonnull_ref_or_enomem(new (nothrow) JaktInternal::ArrayStorage<U>));
+    MUST(storage->push_values(__data, Size));
+    return JaktInternal::ArrayIterator<U> { move(storage), 0, Size };
+}
+}
+
 namespace Jakt {
 using JaktInternal::Array;
 using JaktInternal::ArrayIterator;
@@ -0,0 +1,64 @@
+#pragma once
+
+#include <Jakt/Error.h>
+#include <Jakt/NonnullRefPtr.h>
+#include <Jakt/RefCounted.h>
+#include <Jakt/String.h>
+
+namespace JaktInternal {
+using namespace Jakt;
+
+// A heap allocation holding a single value, so that a struct can refer to its own type.
+// The value is stored out of line so that `Box<T>` can be named while `T` is still incomplete.
+// Copies of a box share the allocation until one of them is set, so a box behaves like the value it holds.
+template<typename T>
+class Box final {
+    struct Storage final : public RefCounted<Storage> {
+        explicit Storage(T value)
+            : value(move(value))
+        {
+        }
+
+        T value;
+    };
+
+public:
+    static ErrorOr<Box> create(T value)
+    {
+        return Box { TRY(adopt_nonnull_ref_or_enomem(new (nothrow) Storage(move(value)))) };
+    }
+
+    T get() const { return m_storage->value; }
+
+    ErrorOr<void> set(T value)
+    {
+        if (m_storage->ref_count() > 1) {
+            m_storage = TRY(adopt_nonnull_ref_or_enomem(new (nothrow) Storage(move(value))));
+            return {};
+        }
+        m_storage->value = move(value);
+        return {};
+    }
+
+private:
+    explicit Box(NonnullRefPtr<Storage> storage)
+        : m_storage(move(storage))
+    {
+    }
+
+    NonnullRefPtr<Storage> m_storage;
+};
+}
+
+namespace Jakt {
+using JaktInternal::Box;
+
+template<typename T>
+struct Formatter<JaktInternal::Box<T>> : Formatter<StringView> {
+    ErrorOr<void> format(FormatBuilder& builder, JaktInternal::Box<T> const& value)
+    {
+        auto str = TRY(Jakt::String::formatted(m_alternative_form ? "Box({:#})" : "Box({})", value.get()));
+        return Formatter<StringView>::format(builder, str);
+    }
+};
+}
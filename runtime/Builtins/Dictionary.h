@@ -77,6 +77,16 @@ public:
         return keys;
     }
 
+    ErrorOr<Array<V>> values() const
+    {
+        Array<V> values = TRY(Array<V>::create_empty());
+        TRY(values.ensure_capacity(m_storage->map.size()));
+        for (auto& it : m_storage->map) {
+            MUST(values.push(it.value));
+        }
+        return values;
+    }
+
     ErrorOr<void> ensure_capacity(size_t capacity)
     {
         TRY(m_storage->map.ensure_capacity(capacity));
@@ -108,6 +118,15 @@ public:
         return dictionary;
     }
 
+    ErrorOr<Dictionary> clone() const
+    {
+        auto dictionary = TRY(create_empty());
+        TRY(dictionary.ensure_capacity(size()));
+        for (auto& it : m_storage->map)
+            TRY(dictionary.set(it.key, it.value));
+        return dictionary;
+    }
+
     DictionaryIterator<K, V> iterator() const { return DictionaryIterator<K, V> { m_storage }; }
 
 private:
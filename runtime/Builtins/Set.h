@@ -85,8 +85,27 @@ public:
         return set;
     }
 
+    ErrorOr<Set> clone() const
+    {
+        auto set = TRY(create_empty());
+        TRY(set.ensure_capacity(size()));
+        for (auto& value : m_storage->table)
+            TRY(set.add(value));
+        return set;
+    }
+
     SetIterator<T> iterator() const { return SetIterator<T> { m_storage }; }
 
+    ErrorOr<Array<T>> values() const
+    {
+        Array<T> values = TRY(Array<T>::create_empty());
+        TRY(values.ensure_capacity(m_storage->table.size()));
+        for (auto& value : m_storage->table) {
+            MUST(values.push(value));
+        }
+        return values;
+    }
+
 private:
     explicit Set(NonnullRefPtr<Storage> storage)
         : m_storage(move(storage))
@@ -0,0 +1,114 @@
+/*
+ * SPDX-License-Identifier: BSD-2-Clause
+ */
+
+#pragma once
+
+#include <Jakt/Error.h>
+#include <Jakt/String.h>
+#include <Jakt/StringBuilder.h>
+#include <filesystem>
+#include <stdlib.h>
+#include <system_error>
+#ifdef _WIN32
+#    include <direct.h>
+#    include <io.h>
+#else
+#    include <sys/wait.h>
+#    include <unistd.h>
+#endif
+
+// Helpers for the parts of running commands and managing files that differ between POSIX hosts and Windows.
+namespace Jakt::os {
+
+inline bool is_terminal(int fd)
+{
+#ifdef _WIN32
+    return _isatty(fd) != 0;
+#else
+    return isatty(fd) != 0;
+#endif
+}
+
+inline bool is_posix_host()
+{
+#ifdef _WIN32
+    return false;
+#else
+    return true;
+#endif
+}
+
+// Quotes an argument so that the host's shell passes it to the command unchanged.
+inline ErrorOr<String> shell_quote(String const& argument)
+{
+    auto builder = TRY(StringBuilder::create());
+#ifdef _WIN32
+    TRY(builder.append('"'));
+    for (size_t i = 0; i < argument.length(); ++i) {
+        if (argument.byte_at(i) == '"')
+            TRY(builder.append('\\'));
+        TRY(builder.append(static_cast<char>(argument.byte_at(i))));
+    }
+    TRY(builder.append('"'));
+#else
+    TRY(builder.append('\''));
+    for (size_t i = 0; i < argument.length(); ++i) {
+        if (argument.byte_at(i) == '\'')
+            TRY(builder.append("'\\''", 4));
+        else
+            TRY(builder.append(static_cast<char>(argument.byte_at(i))));
+    }
+    TRY(builder.append('\''));
+#endif
+    return builder.to_string();
+}
+
+// Runs a command through the host's shell and returns its exit code, or 128 plus the signal number if it was killed.
+inline int run_command(String const& command)
+{
+    int status = system(command.c_string());
+#ifdef _WIN32
+    return status;
+#else
+    if (status == -1)
+        return 127;
+    if (WIFSIGNALED(status))
+        return 128 + WTERMSIG(status);
+    return WEXITSTATUS(status);
+#endif
+}
+
+// Creates a new directory that only the current user can access, and returns its path.
+inline ErrorOr<String> make_temporary_directory()
+{
+    std::error_code error;
+    auto base = std::filesystem::temp_directory_path(error);
+    if (error)
+        return Error::from_errno(error.value());
+    auto path_template = (base / "jakt-XXXXXX").string();
+#ifdef _WIN32
+    if (_mktemp_s(path_template.data(), path_template.size() + 1) != 0 || _mkdir(path_template.c_str()) != 0)
+        return Error::from_errno(errno);
+#else
+    if (!mkdtemp(path_template.data()))
+        return Error::from_errno(errno);
+#endif
+    return String::from_c_string(path_template.c_str());
+}
+
+inline bool make_directory(String const& path)
+{
+    std::error_code error;
+    std::filesystem::create_directories(path.c_string(), error);
+    return !error;
+}
+
+inline bool remove_directory(String const& path)
+{
+    std::error_code error;
+    std::filesystem::remove_all(path.c_string(), error);
+    return !error;
+}
+
+}
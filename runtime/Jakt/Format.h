@@ -79,7 +79,8 @@ struct TypeErasedParameter {
     template<typename T>
     static consteval Type get_type()
     {
-        if constexpr (IsIntegral<T>)
+        // 128-bit integers can't be used as format parameters such as widths, so they're only formatted as values.
+        if constexpr (IsIntegral<T> && sizeof(T) <= sizeof(u64))
             return get_type_from_size<sizeof(T), IsUnsigned<T>>();
         else
             return Type::Custom;
@@ -402,6 +403,40 @@ struct Formatter<bool> : StandardFormatter {
     ErrorOr<void> format(FormatBuilder&, bool);
 };
 
+#ifdef __SIZEOF_INT128__
+// FormatBuilder only knows how to put 64-bit integers, so 128-bit ones are turned into decimal digits here.
+template<>
+struct Formatter<unsigned __int128> : Formatter<StringView> {
+    ErrorOr<void> format(FormatBuilder& builder, unsigned __int128 value)
+    {
+        return format_digits(builder, value, false);
+    }
+
+    ErrorOr<void> format_digits(FormatBuilder& builder, unsigned __int128 magnitude, bool is_negative)
+    {
+        char buffer[41];
+        size_t start = sizeof(buffer);
+        do {
+            buffer[--start] = static_cast<char>('0' + static_cast<int>(magnitude % 10));
+            magnitude /= 10;
+        } while (magnitude != 0);
+        if (is_negative)
+            buffer[--start] = '-';
+        return Formatter<StringView>::format(builder, StringView { buffer + start, sizeof(buffer) - start });
+    }
+};
+
+template<>
+struct Formatter<__int128> : Formatter<unsigned __int128> {
+    ErrorOr<void> format(FormatBuilder& builder, __int128 value)
+    {
+        if (value < 0)
+            return format_digits(builder, static_cast<unsigned __int128>(0) - static_cast<unsigned __int128>(value), true);
+        return format_digits(builder, static_cast<unsigned __int128>(value), false);
+    }
+};
+#endif
+
 #ifndef KERNEL
 template<>
 struct Formatter<float> : StandardFormatter {
@@ -48,8 +48,6 @@ inline constexpr bool IsFunctionObject = (!IsFunctionPointer<F> && IsRvalueRefer
 
 template<typename Out, typename... In>
 class Function<Out(In...)> {
-    AK_MAKE_NONCOPYABLE(Function);
-
 public:
     Function() = default;
     Function(std::nullptr_t)
@@ -61,8 +59,9 @@ public:
         clear(false);
     }
 
+    // Functions can be copied, so only callables that can be copied themselves are accepted.
     template<typename CallableType>
-    Function(CallableType&& callable) requires((IsFunctionObject<CallableType> && IsCallableWithArguments<CallableType, In...> && !IsSame<RemoveCVReference<CallableType>, Function>))
+    Function(CallableType&& callable) requires((IsFunctionObject<CallableType> && IsCallableWithArguments<CallableType, In...> && IsCopyConstructible<RemoveCVReference<CallableType>> && !IsSame<RemoveCVReference<CallableType>, Function>))
     {
         init_with_callable(forward<CallableType>(callable));
     }
@@ -73,6 +72,11 @@ public:
         init_with_callable(move(f));
     }
 
+    Function(Function const& other)
+    {
+        copy_from(other);
+    }
+
     Function(Function&& other)
     {
         move_from(move(other));
@@ -94,7 +98,7 @@ public:
     explicit operator bool() const { return !!callable_wrapper(); }
 
     template<typename CallableType>
-    Function& operator=(CallableType&& callable) requires((IsFunctionObject<CallableType> && IsCallableWithArguments<CallableType, In...>))
+    Function& operator=(CallableType&& callable) requires((IsFunctionObject<CallableType> && IsCallableWithArguments<CallableType, In...> && IsCopyConstructible<RemoveCVReference<CallableType>>))
     {
         clear();
         init_with_callable(forward<CallableType>(callable));
@@ -116,6 +120,15 @@ public:
         return *this;
     }
 
+    Function& operator=(Function const& other)
+    {
+        if (this != &other) {
+            clear();
+            copy_from(other);
+        }
+        return *this;
+    }
+
     Function& operator=(Function&& other)
     {
         if (this != &other) {
@@ -133,6 +146,8 @@ private:
         virtual Out call(In...) = 0;
         virtual void destroy() = 0;
         virtual void init_and_swap(u8*, size_t) = 0;
+        virtual void init_and_copy(u8*, size_t) const = 0;
+        virtual CallableWrapperBase* clone() const = 0;
     };
 
     template<typename CallableType>
@@ -163,6 +178,17 @@ private:
             new (destination) CallableWrapper { move(m_callable) };
         }
 
+        void init_and_copy(u8* destination, size_t size) const final override
+        {
+            VERIFY(size >= sizeof(CallableWrapper));
+            new (destination) CallableWrapper { CallableType { m_callable } };
+        }
+
+        CallableWrapperBase* clone() const final override
+        {
+            return new CallableWrapper { CallableType { m_callable } };
+        }
+
     private:
         CallableType m_callable;
     };
@@ -243,6 +269,26 @@ private:
         other.m_kind = FunctionKind::NullPointer;
     }
 
+    // Copies share nothing with the original; each one owns its own copy of the callable and its captures.
+    void copy_from(Function const& other)
+    {
+        auto* other_wrapper = other.callable_wrapper();
+        switch (other.m_kind) {
+        case FunctionKind::NullPointer:
+            break;
+        case FunctionKind::Inline:
+            other_wrapper->init_and_copy(m_storage, inline_capacity);
+            m_kind = FunctionKind::Inline;
+            break;
+        case FunctionKind::Outline:
+            *bit_cast<CallableWrapperBase**>(&m_storage) = other_wrapper->clone();
+            m_kind = FunctionKind::Outline;
+            break;
+        default:
+            VERIFY_NOT_REACHED();
+        }
+    }
+
     FunctionKind m_kind { FunctionKind::NullPointer };
     bool m_deferred_clear { false };
     mutable Atomic<u16> m_call_nesting_level { 0 };
@@ -9,6 +9,11 @@
 #include <Jakt/Iterator.h>
 #include <Jakt/Span.h>
 
+namespace JaktInternal {
+template<typename T>
+class ArrayIterator;
+}
+
 namespace Jakt {
 
 template<typename T, size_t Size>
@@ -42,6 +47,19 @@ struct LinearArray {
 
     [[nodiscard]] constexpr bool is_empty() const { return size() == 0; }
 
+    [[nodiscard]] constexpr bool contains(T const& value) const
+    {
+        for (size_t i = 0; i < Size; ++i) {
+            if (__data[i] == value)
+                return true;
+        }
+        return false;
+    }
+
+    // Iterates over a copy of the values, so the iterator can outlive the array. Defined in <Builtins/Array.h>.
+    template<typename U = T>
+    [[nodiscard]] JaktInternal::ArrayIterator<U> iterator() const;
+
     [[nodiscard]] constexpr T const& operator[](size_t index) const { return at(index); }
     [[nodiscard]] constexpr T& operator[](size_t index) { return at(index); }
 
@@ -200,6 +200,12 @@ public:
     operator bool() const = delete;
     bool operator!() const = delete;
 
+    // Two references are equal when they point at the same object.
+    template<typename U>
+    bool operator==(NonnullRefPtr<U> const& other) const { return ptr() == other.ptr(); }
+    template<typename U>
+    bool operator!=(NonnullRefPtr<U> const& other) const { return ptr() != other.ptr(); }
+
     void swap(NonnullRefPtr& other)
     {
         Jakt::swap(m_ptr, other.m_ptr);
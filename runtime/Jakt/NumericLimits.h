@@ -98,6 +98,22 @@ struct NumericLimits<unsigned long long> {
     static constexpr bool is_signed() { return false; }
 };
 
+#ifdef __SIZEOF_INT128__
+template<>
+struct NumericLimits<__int128> {
+    static constexpr __int128 min() { return -max() - 1; }
+    static constexpr __int128 max() { return static_cast<__int128>(~static_cast<unsigned __int128>(0) >> 1); }
+    static constexpr bool is_signed() { return true; }
+};
+
+template<>
+struct NumericLimits<unsigned __int128> {
+    static constexpr unsigned __int128 min() { return 0; }
+    static constexpr unsigned __int128 max() { return ~static_cast<unsigned __int128>(0); }
+    static constexpr bool is_signed() { return false; }
+};
+#endif
+
 #ifndef KERNEL
 template<>
 struct NumericLimits<float> {
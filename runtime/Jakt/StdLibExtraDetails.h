@@ -272,6 +272,16 @@ template<>
 struct __MakeUnsigned<bool> {
     using Type = bool;
 };
+#ifdef __SIZEOF_INT128__
+template<>
+struct __MakeUnsigned<__int128> {
+    using Type = unsigned __int128;
+};
+template<>
+struct __MakeUnsigned<unsigned __int128> {
+    using Type = unsigned __int128;
+};
+#endif
 
 template<typename T>
 using MakeUnsigned = typename __MakeUnsigned<T>::Type;
@@ -324,6 +334,16 @@ template<>
 struct __MakeSigned<char> {
     using Type = char;
 };
+#ifdef __SIZEOF_INT128__
+template<>
+struct __MakeSigned<__int128> {
+    using Type = __int128;
+};
+template<>
+struct __MakeSigned<unsigned __int128> {
+    using Type = __int128;
+};
+#endif
 
 template<typename T>
 using MakeSigned = typename __MakeSigned<T>::Type;
@@ -394,6 +414,10 @@ template<>
 inline constexpr bool __IsIntegral<unsigned long> = true;
 template<>
 inline constexpr bool __IsIntegral<unsigned long long> = true;
+#ifdef __SIZEOF_INT128__
+template<>
+inline constexpr bool __IsIntegral<unsigned __int128> = true;
+#endif
 
 template<typename T>
 inline constexpr bool IsIntegral = __IsIntegral<MakeUnsigned<RemoveCV<T>>>;
@@ -106,6 +106,7 @@ public:
 
     [[nodiscard]] static String empty() { return String { StringStorage::the_empty_string() }; }
     static ErrorOr<String> from_utf8(StringView);
+    static ErrorOr<String> from_c_string(char const* c_string) { return copy(StringView { c_string, strlen(c_string) }); }
     static ErrorOr<String> copy(StringView);
 
     [[nodiscard]] static ErrorOr<String> vformatted(StringView fmtstr, TypeErasedFormatParams&);
@@ -145,6 +146,7 @@ public:
     
     // Guaranteed to include null terminator.
     [[nodiscard]] char const* c_string() const { return m_storage->c_string(); }
+    [[nodiscard]] char const* to_c_string() const { return c_string(); }
 
     [[nodiscard]] ALWAYS_INLINE char const& operator[](size_t i) const
     {
@@ -11,6 +11,7 @@
 #include <Builtins/Set.h>
 #include <Jakt/Format.h>
 #include <Jakt/Forward.h>
+#include <Jakt/Function.h>
 #include <Jakt/String.h>
 #include <Jakt/StringView.h>
 #include <Jakt/Tuple.h>
@@ -136,6 +137,29 @@ struct Formatter<JaktInternal::ArraySlice<T>> : Formatter<StringView> {
     }
 };
 
+template<typename T, size_t Size>
+struct Formatter<LinearArray<T, Size>> : Formatter<StringView> {
+    ErrorOr<void> format(FormatBuilder& builder, LinearArray<T, Size> const& value)
+    {
+        JaktInternal::PrettyPrint::ScopedEnable pretty_print_enable { m_alternative_form };
+
+        auto string_builder = TRY(StringBuilder::create());
+        TRY(string_builder.append("["));
+        {
+            JaktInternal::PrettyPrint::ScopedLevelIncrease increase_indent {};
+            for (size_t i = 0; i < Size; ++i) {
+                TRY(JaktInternal::PrettyPrint::output_indentation(string_builder));
+                TRY(append_value(string_builder, value[i], m_alternative_form));
+                if (i != Size - 1)
+                    TRY(string_builder.append(", "));
+            }
+        }
+        TRY(string_builder.append("]"));
+
+        return Formatter<StringView>::format(builder, TRY(string_builder.to_string()));
+    }
+};
+
 template<typename T>
 struct Formatter<JaktInternal::ArrayIterator<T>> : Formatter<StringView> {
     ErrorOr<void> format(FormatBuilder& builder, JaktInternal::ArrayIterator<T> const& value)
@@ -246,6 +270,14 @@ struct Formatter<JaktInternal::DictionaryIterator<K, V>> : Formatter<StringView>
     }
 };
 
+template<typename Out, typename... In>
+struct Formatter<Jakt::Function<Out(In...)>> : Formatter<StringView> {
+    ErrorOr<void> format(FormatBuilder& builder, Jakt::Function<Out(In...)> const&)
+    {
+        return Formatter<StringView>::format(builder, "<function>"sv);
+    }
+};
+
 template<typename... Ts>
 struct Formatter<Jakt::Tuple<Ts...>> : Formatter<StringView> {
     ErrorOr<void> format(FormatBuilder& builder, Jakt::Tuple<Ts...> const& tuple)
@@ -36,6 +36,8 @@ requires(IsIntegral<T>) struct Traits<T> : public GenericTraits<T> {
     {
         if constexpr (sizeof(T) < 8)
             return int_hash(value);
+        else if constexpr (sizeof(T) > 8)
+            return pair_int_hash(u64_hash(static_cast<u64>(value)), u64_hash(static_cast<u64>(value >> 64)));
         else
             return u64_hash(value);
     }
@@ -51,4 +51,32 @@ ALWAYS_INLINE CopyConst<InputType, OutputType>& verify_cast(InputType& input)
     return static_cast<CopyConst<InputType, OutputType>&>(input);
 }
 
+template<typename OutputType, typename InputType>
+requires(IsSpecializationOf<OutputType, NonnullRefPtr>)
+ALWAYS_INLINE OutputType verify_cast(NonnullRefPtr<InputType> const& input)
+{
+    using Target = typename OutputType::ElementType;
+    if constexpr (IsBaseOf<Target, InputType>) {
+        return OutputType(input);
+    } else {
+        static_assert(IsBaseOf<InputType, Target>);
+        VERIFY(is<Target>(*input));
+        return OutputType(static_cast<Target const&>(*input));
+    }
+}
+
+template<typename OutputType, typename InputType>
+requires(IsSpecializationOf<OutputType, NonnullRefPtr>)
+ALWAYS_INLINE Optional<OutputType> fallible_class_cast(NonnullRefPtr<InputType> const& input)
+{
+    using Target = typename OutputType::ElementType;
+    if constexpr (IsBaseOf<Target, InputType>) {
+        return OutputType(input);
+    } else {
+        if (!is<Target>(*input))
+            return {};
+        return OutputType(static_cast<Target const&>(*input));
+    }
+}
+
 }
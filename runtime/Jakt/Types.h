@@ -18,6 +18,14 @@ using i32 = __INT32_TYPE__;
 using i16 = __INT16_TYPE__;
 using i8 = __INT8_TYPE__;
 
+#ifdef __SIZEOF_INT128__
+using u128 = unsigned __int128;
+using i128 = __int128;
+#endif
+
+// Jakt's `c_string`: a NUL-terminated string owned by someone else.
+using c_string = char const*;
+
 #ifdef _WIN32
 using ssize_t = ::Jakt::MakeSigned<size_t>;
 #endif
@@ -50,6 +50,8 @@
 #include <Jakt/kmalloc.h>
 #include <Jakt/kstdio.h>
 
+// Programs linking against a Jakt library get these definitions from the library.
+#ifndef JAKT_USING_LIBRARY
 #include <Jakt/Format.cpp>
 #include <Jakt/GenericLexer.cpp>
 #include <Jakt/String.cpp>
@@ -58,6 +60,7 @@
 #include <Jakt/StringView.cpp>
 #include <Jakt/kmalloc.cpp>
 #include <Jakt/PrettyPrint.cpp>
+#endif
 
 namespace JaktInternal {
 template<typename T>
@@ -65,12 +68,17 @@ class Set;
 }
 
 #include <Builtins/Array.h>
+#include <Builtins/Box.h>
 #include <Builtins/Dictionary.h>
 #include <Builtins/Set.h>
 
+#ifndef JAKT_TARGET_WASM32
 #include <IO/File.h>
 
+#ifndef JAKT_USING_LIBRARY
 #include <IO/File.cpp>
+#endif
+#endif
 
 using f32 = float;
 using f64 = double;
@@ -93,6 +101,14 @@ inline void panic(StringView message)
     VERIFY_NOT_REACHED();
 }
 
+// `location` is where the failing operation appears in the Jakt source, if the compiler passed one.
+inline void panic(StringView message, char const* location)
+{
+    if (!location)
+        return panic(message);
+    panic(MUST(String::formatted("{} at {}", message, location)));
+}
+
 [[noreturn]] inline void abort()
 {
     ::abort();
@@ -110,64 +126,199 @@ inline constexpr T unchecked_mul(T value, T other)
     return value * other;
 }
 
+// The panics below are wrapped in lambdas so that these stay usable in constant expressions
+// (such as initializers of `const` declarations) as long as no overflow happens.
 template<typename T>
-inline constexpr T checked_add(T value, T other)
+inline constexpr T checked_add(T value, T other, char const* location = nullptr)
 {
     Checked<T> checked = value;
     checked += other;
     if (checked.has_overflow())
-        panic(MUST(String::formatted("Overflow in checked addition '{} + {}'", value, other)));
+        [&] { panic(MUST(String::formatted("Overflow in checked addition '{} + {}'", value, other)), location); }();
     return checked.value_unchecked();
 }
 
 template<typename T>
-inline constexpr T checked_sub(T value, T other)
+inline constexpr T checked_sub(T value, T other, char const* location = nullptr)
 {
     Checked<T> checked = value;
     checked -= other;
     if (checked.has_overflow())
-        panic(MUST(String::formatted("Overflow in checked subtraction '{} - {}'", value, other)));
+        [&] { panic(MUST(String::formatted("Overflow in checked subtraction '{} - {}'", value, other)), location); }();
     return checked.value_unchecked();
 }
 
 template<typename T>
-inline constexpr T checked_mul(T value, T other)
+inline constexpr T checked_mul(T value, T other, char const* location = nullptr)
 {
     Checked<T> checked = value;
     checked *= other;
     if (checked.has_overflow())
-        panic(MUST(String::formatted("Overflow in checked multiplication '{} * {}'", value, other)));
+        [&] { panic(MUST(String::formatted("Overflow in checked multiplication '{} * {}'", value, other)), location); }();
     return checked.value_unchecked();
 }
 
 template<typename T>
-inline constexpr T checked_div(T value, T other)
+inline constexpr T checked_div(T value, T other, char const* location = nullptr)
 {
     Checked<T> checked = value;
     checked /= other;
     if (checked.has_overflow()) {
         if (other == 0)
-            panic(MUST(String::formatted("Division by zero in checked division '{} / {}'", value, other)));
+            [&] { panic(MUST(String::formatted("Division by zero in checked division '{} / {}'", value, other)), location); }();
         else
-            panic(MUST(String::formatted("Overflow in checked division '{} / {}'", value, other)));
+            [&] { panic(MUST(String::formatted("Overflow in checked division '{} / {}'", value, other)), location); }();
     }
     return checked.value_unchecked();
 }
 
 template<typename T>
-inline constexpr T checked_mod(T value, T other)
+inline constexpr T checked_mod(T value, T other, char const* location = nullptr)
 {
     Checked<T> checked = value;
     checked %= other;
     if (checked.has_overflow()) {
         if (other == 0)
-            panic(MUST(String::formatted("Division by zero in checked modulo '{} % {}'", value, other)));
+            [&] { panic(MUST(String::formatted("Division by zero in checked modulo '{} % {}'", value, other)), location); }();
         else
-            panic(MUST(String::formatted("Overflow in checked modulo '{} % {}'", value, other)));
+            [&] { panic(MUST(String::formatted("Overflow in checked modulo '{} % {}'", value, other)), location); }();
+    }
+    return checked.value_unchecked();
+}
+
+// Wrapping operations are done in an unsigned type at least as wide as `int`, where overflow is defined to wrap around.
+template<typename T>
+using WrappingArithmeticType = Conditional<(sizeof(T) < sizeof(unsigned)), unsigned, MakeUnsigned<T>>;
+
+template<typename T>
+inline constexpr T wrapping_add(T value, T other)
+{
+    return static_cast<T>(static_cast<WrappingArithmeticType<T>>(value) + static_cast<WrappingArithmeticType<T>>(other));
+}
+
+template<typename T>
+inline constexpr T wrapping_sub(T value, T other)
+{
+    return static_cast<T>(static_cast<WrappingArithmeticType<T>>(value) - static_cast<WrappingArithmeticType<T>>(other));
+}
+
+template<typename T>
+inline constexpr T wrapping_mul(T value, T other)
+{
+    return static_cast<T>(static_cast<WrappingArithmeticType<T>>(value) * static_cast<WrappingArithmeticType<T>>(other));
+}
+
+template<typename T>
+inline constexpr T saturating_add(T value, T other)
+{
+    Checked<T> checked = value;
+    checked += other;
+    if (!checked.has_overflow())
+        return checked.value_unchecked();
+    if constexpr (IsSigned<T>) {
+        if (other < 0)
+            return NumericLimits<T>::min();
     }
+    return NumericLimits<T>::max();
+}
+
+template<typename T>
+inline constexpr T saturating_sub(T value, T other)
+{
+    Checked<T> checked = value;
+    checked -= other;
+    if (!checked.has_overflow())
+        return checked.value_unchecked();
+    if constexpr (IsSigned<T>) {
+        if (other < 0)
+            return NumericLimits<T>::max();
+    }
+    return NumericLimits<T>::min();
+}
+
+template<typename T>
+inline constexpr T saturating_mul(T value, T other)
+{
+    Checked<T> checked = value;
+    checked *= other;
+    if (!checked.has_overflow())
+        return checked.value_unchecked();
+    if constexpr (IsSigned<T>) {
+        if ((value < 0) != (other < 0))
+            return NumericLimits<T>::min();
+    }
+    return NumericLimits<T>::max();
+}
+
+template<typename T>
+inline constexpr Optional<T> fallible_add(T value, T other)
+{
+    Checked<T> checked = value;
+    checked += other;
+    if (checked.has_overflow())
+        return {};
+    return checked.value_unchecked();
+}
+
+template<typename T>
+inline constexpr Optional<T> fallible_sub(T value, T other)
+{
+    Checked<T> checked = value;
+    checked -= other;
+    if (checked.has_overflow())
+        return {};
+    return checked.value_unchecked();
+}
+
+template<typename T>
+inline constexpr Optional<T> fallible_mul(T value, T other)
+{
+    Checked<T> checked = value;
+    checked *= other;
+    if (checked.has_overflow())
+        return {};
+    return checked.value_unchecked();
+}
+
+template<typename T>
+inline constexpr Optional<T> fallible_div(T value, T other)
+{
+    Checked<T> checked = value;
+    checked /= other;
+    if (checked.has_overflow())
+        return {};
     return checked.value_unchecked();
 }
 
+template<typename T>
+inline constexpr T checked_pow(T base, T exponent)
+{
+    if constexpr (IsFloatingPoint<T>) {
+        if constexpr (IsSame<T, float>)
+            return __builtin_powf(base, exponent);
+        else
+            return __builtin_pow(base, exponent);
+    } else {
+        if constexpr (IsSigned<T>) {
+            if (exponent < 0)
+                [&] { panic(MUST(String::formatted("Negative exponent in checked exponentiation '{} ** {}'", base, exponent))); }();
+            if (base == -1)
+                return (exponent % 2 == 0) ? 1 : -1;
+        }
+        if (base == 0 || base == 1)
+            return exponent == 0 ? 1 : base;
+
+        // |base| >= 2 here, so this overflows after at most 64 iterations.
+        Checked<T> checked = 1;
+        for (T i = 0; i < exponent; ++i) {
+            checked *= base;
+            if (checked.has_overflow())
+                [&] { panic(MUST(String::formatted("Overflow in checked exponentiation '{} ** {}'", base, exponent))); }();
+        }
+        return checked.value_unchecked();
+    }
+}
+
 template<typename T>
 inline constexpr T arithmetic_shift_right(T value, size_t steps)
 {
@@ -400,17 +551,39 @@ ALWAYS_INLINE decltype(auto) deref_if_ref_pointer(T&& value)
         return static_cast<Conditional<IsRvalueReference<T>, RemoveReference<T>, T>>(value);
 }
 
+// Globals are initialized before main is entered, so an error thrown by an initializer ends the program right away.
+template<typename Callback>
+auto initialize_global(Callback callback)
+{
+    auto result = callback();
+    if (result.is_error()) {
+        warnln("Runtime error: {}", result.error());
+        ::exit(1);
+    }
+    return result.release_value();
+}
+
 }
 
 namespace Jakt {
 using JaktInternal::abort;
 using JaktInternal::as_saturated;
 using JaktInternal::as_truncated;
+using JaktInternal::fallible_add;
+using JaktInternal::fallible_div;
 using JaktInternal::fallible_integer_cast;
+using JaktInternal::fallible_mul;
+using JaktInternal::fallible_sub;
 using JaktInternal::infallible_integer_cast;
 using JaktInternal::Range;
+using JaktInternal::saturating_add;
+using JaktInternal::saturating_mul;
+using JaktInternal::saturating_sub;
 using JaktInternal::unchecked_add;
 using JaktInternal::unchecked_mul;
+using JaktInternal::wrapping_add;
+using JaktInternal::wrapping_mul;
+using JaktInternal::wrapping_sub;
 }
 
 // We place main in a separate namespace to ensure it has access to the same identifiers as other functions
@@ -418,6 +591,8 @@ namespace Jakt {
 ErrorOr<int> main(Array<String>);
 }
 
+// Libraries are linked into a program that has its own main().
+#if !defined(JAKT_LIBRARY) && !defined(JAKT_USING_LIBRARY)
 int main(int argc, char** argv)
 {
     auto args = MUST(Jakt::Array<Jakt::String>::create_empty());
@@ -431,3 +606,4 @@ int main(int argc, char** argv)
     }
     return result.value();
 }
+#endif
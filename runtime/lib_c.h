@@ -0,0 +1,137 @@
+/*
+ * Runtime support for the C code generated by `jakt --backend=c`.
+ *
+ * SPDX-License-Identifier: BSD-2-Clause
+ */
+
+#ifndef JAKT_LIB_C_H
+#define JAKT_LIB_C_H
+
+#include <inttypes.h>
+#include <limits.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#if !defined(__GNUC__)
+#    error "The Jakt C runtime needs a compiler with __builtin_add_overflow() and friends, such as GCC or Clang"
+#endif
+
+typedef uint8_t u8;
+typedef uint16_t u16;
+typedef uint32_t u32;
+typedef uint64_t u64;
+typedef int8_t i8;
+typedef int16_t i16;
+typedef int32_t i32;
+typedef int64_t i64;
+typedef float f32;
+typedef double f64;
+
+/* `location` is where the failing operation appears in the Jakt source, if the compiler passed one. */
+static inline void jakt_panic(char const* message, char const* location)
+{
+    if (location)
+        fprintf(stderr, "Panic: %s at %s\n", message, location);
+    else
+        fprintf(stderr, "Panic: %s\n", message);
+#ifndef JAKT_CONTINUE_ON_PANIC
+    abort();
+#endif
+}
+
+/*
+ * Checked and wrapping arithmetic for each integer type T, named like jakt_checked_add_i32().
+ * Wrapping operations are done in the unsigned type U, at least as wide as `int`, where overflow is defined to wrap around.
+ */
+#define JAKT_DEFINE_ARITHMETIC(T, U, MIN)                                                            \
+    static inline T jakt_checked_add_##T(T value, T other, char const* location)                     \
+    {                                                                                                \
+        T result;                                                                                    \
+        if (__builtin_add_overflow(value, other, &result))                                           \
+            jakt_panic("Overflow in checked addition", location);                                    \
+        return result;                                                                               \
+    }                                                                                                \
+    static inline T jakt_checked_sub_##T(T value, T other, char const* location)                     \
+    {                                                                                                \
+        T result;                                                                                    \
+        if (__builtin_sub_overflow(value, other, &result))                                           \
+            jakt_panic("Overflow in checked subtraction", location);                                 \
+        return result;                                                                               \
+    }                                                                                                \
+    static inline T jakt_checked_mul_##T(T value, T other, char const* location)                     \
+    {                                                                                                \
+        T result;                                                                                    \
+        if (__builtin_mul_overflow(value, other, &result))                                           \
+            jakt_panic("Overflow in checked multiplication", location);                              \
+        return result;                                                                               \
+    }                                                                                                \
+    static inline T jakt_checked_div_##T(T value, T other, char const* location)                     \
+    {                                                                                                \
+        if (other == 0) {                                                                            \
+            jakt_panic("Division by zero in checked division", location);                            \
+            return 0;                                                                                \
+        }                                                                                            \
+        if (MIN != 0 && value == MIN && other == (T)-1) {                                            \
+            jakt_panic("Overflow in checked division", location);                                    \
+            return value;                                                                            \
+        }                                                                                            \
+        return value / other;                                                                        \
+    }                                                                                                \
+    static inline T jakt_checked_mod_##T(T value, T other, char const* location)                     \
+    {                                                                                                \
+        if (other == 0) {                                                                            \
+            jakt_panic("Division by zero in checked modulo", location);                              \
+            return 0;                                                                                \
+        }                                                                                            \
+        if (MIN != 0 && value == MIN && other == (T)-1) {                                            \
+            jakt_panic("Overflow in checked modulo", location);                                      \
+            return 0;                                                                                \
+        }                                                                                            \
+        return value % other;                                                                        \
+    }                                                                                                \
+    static inline T jakt_wrapping_add_##T(T value, T other)                                          \
+    {                                                                                                \
+        return (T)((U)value + (U)other);                                                             \
+    }                                                                                                \
+    static inline T jakt_wrapping_sub_##T(T value, T other)                                          \
+    {                                                                                                \
+        return (T)((U)value - (U)other);                                                             \
+    }                                                                                                \
+    static inline T jakt_wrapping_mul_##T(T value, T other)                                          \
+    {                                                                                                \
+        return (T)((U)value * (U)other);                                                             \
+    }
+
+JAKT_DEFINE_ARITHMETIC(u8, unsigned, 0)
+JAKT_DEFINE_ARITHMETIC(u16, unsigned, 0)
+JAKT_DEFINE_ARITHMETIC(u32, uint32_t, 0)
+JAKT_DEFINE_ARITHMETIC(u64, uint64_t, 0)
+JAKT_DEFINE_ARITHMETIC(i8, unsigned, INT8_MIN)
+JAKT_DEFINE_ARITHMETIC(i16, unsigned, INT16_MIN)
+JAKT_DEFINE_ARITHMETIC(i32, uint32_t, INT32_MIN)
+JAKT_DEFINE_ARITHMETIC(i64, uint64_t, INT64_MIN)
+JAKT_DEFINE_ARITHMETIC(size_t, size_t, 0)
+JAKT_DEFINE_ARITHMETIC(int, unsigned, INT_MIN)
+JAKT_DEFINE_ARITHMETIC(char, unsigned, CHAR_MIN)
+
+#undef JAKT_DEFINE_ARITHMETIC
+
+/* Integer conversions that can lose information panic when the value doesn't fit into the range of the new type. */
+static inline intmax_t jakt_checked_cast_from_signed(intmax_t value, intmax_t min, uintmax_t max, char const* location)
+{
+    if (value < min || (value > 0 && (uintmax_t)value > max))
+        jakt_panic("Out of bounds in integer cast", location);
+    return value;
+}
+
+static inline uintmax_t jakt_checked_cast_from_unsigned(uintmax_t value, uintmax_t max, char const* location)
+{
+    if (value > max)
+        jakt_panic("Out of bounds in integer cast", location);
+    return value;
+}
+
+#endif
@@ -0,0 +1,17 @@
+/*
+ * Runtime for programs built with `jakt --target wasm32`.
+ *
+ * SPDX-License-Identifier: BSD-2-Clause
+ */
+
+#pragma once
+
+// WebAssembly has no file system of its own, so this leaves out File and everything else that
+// needs one, keeping String, Array and the other builtins that only need memory allocation.
+#ifndef JAKT_TARGET_WASM32
+#    define JAKT_TARGET_WASM32
+#endif
+
+static_assert(sizeof(void*) == 4, "lib_wasm.h is only meant for 32-bit WebAssembly targets");
+
+#include <lib.h>